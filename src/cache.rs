@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::{LintConfig, LintMessage};
+
+const CACHE_DIR: &str = ".fastpy_cache";
+const CACHE_FILE: &str = "cache.json";
+
+/// On-disk cache of per-file lint results, keyed by absolute path. An
+/// entry is reused only while both the file's content hash and the
+/// effective config's hash still match what produced it.
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct Cache {
+    /// Invalidates the whole cache whenever fastpy's version changes,
+    /// since a new version may lint differently.
+    version: String,
+    entries: HashMap<PathBuf, CacheEntry>,
+}
+
+/// Which subcommand produced a cache entry. `check` and `fix` share one
+/// cache file keyed by path, but a `check`-only run never calls
+/// `find_fixes`/writes a fix, so `fix` must not mistake a `check` entry
+/// for proof that there's nothing left to fix.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operation {
+    Check,
+    Fix,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct CacheEntry {
+    source_hash: String,
+    config_hash: String,
+    op: Operation,
+    messages: Vec<CachedMessage>,
+}
+
+/// An owned, serializable stand-in for `LintMessage` (whose `rule` field
+/// is a `&'static str` and so can't round-trip through deserialization).
+#[derive(Serialize, Deserialize, Clone)]
+struct CachedMessage {
+    line: usize,
+    col: usize,
+    end_col: usize,
+    rule: String,
+    message: String,
+}
+
+impl From<&LintMessage> for CachedMessage {
+    fn from(m: &LintMessage) -> Self {
+        CachedMessage {
+            line: m.line,
+            col: m.col,
+            end_col: m.end_col,
+            rule: m.rule.to_string(),
+            message: m.message.clone(),
+        }
+    }
+}
+
+impl CachedMessage {
+    fn into_lint_message(self, file: &str) -> LintMessage {
+        LintMessage {
+            file: file.to_string(),
+            line: self.line,
+            col: self.col,
+            end_col: self.end_col,
+            rule: intern_rule(&self.rule),
+            message: self.message,
+        }
+    }
+}
+
+/// Maps a cached rule id back to the `&'static str` fastpy's rules use.
+/// Unrecognized ids (e.g. from a cache written by a newer fastpy with
+/// rules this build doesn't know) fall back to a generic label rather
+/// than panicking.
+fn intern_rule(id: &str) -> &'static str {
+    match id {
+        "ambiguous-name" => "ambiguous-name",
+        "max-line-length" => "max-line-length",
+        _ => "unknown-rule",
+    }
+}
+
+fn cache_file_path() -> PathBuf {
+    Path::new(CACHE_DIR).join(CACHE_FILE)
+}
+
+fn current_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+/// Hashes file contents (or anything else we need a fast content digest
+/// of, like the serialized config) with blake3.
+pub(crate) fn hash_bytes(bytes: &[u8]) -> String {
+    blake3::hash(bytes).to_hex().to_string()
+}
+
+pub(crate) fn config_hash(config: &LintConfig) -> String {
+    hash_bytes(serde_json::to_string(config).unwrap_or_default().as_bytes())
+}
+
+impl Cache {
+    /// A fresh, empty cache stamped with the current fastpy version.
+    pub(crate) fn empty() -> Cache {
+        Cache {
+            version: current_version(),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Loads the cache from disk, discarding it if it's missing,
+    /// corrupt, or was written by a different fastpy version.
+    pub(crate) fn load() -> Cache {
+        let empty = Cache::empty();
+
+        let Ok(contents) = fs::read_to_string(cache_file_path()) else {
+            return empty;
+        };
+        match serde_json::from_str::<Cache>(&contents) {
+            Ok(cache) if cache.version == current_version() => cache,
+            Ok(_) => {
+                debug!("fastpy version changed; discarding incremental cache");
+                empty
+            }
+            Err(err) => {
+                warn!("Ignoring unreadable incremental cache: {}", err);
+                empty
+            }
+        }
+    }
+
+    pub(crate) fn save(&self) {
+        if let Err(err) = fs::create_dir_all(CACHE_DIR) {
+            warn!("Could not create {:?}: {}", CACHE_DIR, err);
+            return;
+        }
+        match serde_json::to_string(self) {
+            Ok(json) => {
+                if let Err(err) = fs::write(cache_file_path(), json) {
+                    warn!("Could not write incremental cache: {}", err);
+                }
+            }
+            Err(err) => warn!("Could not serialize incremental cache: {}", err),
+        }
+    }
+
+    /// Returns cached diagnostics for `path` if its content and config
+    /// hashes still match *and* the entry was written by `op` itself,
+    /// replaying them with `path` as the file field. An entry written by a
+    /// different operation (e.g. `check` caching a file `fix` now wants)
+    /// is never reused, since `check` never ran `find_fixes`/wrote a fix.
+    pub(crate) fn get(&self, path: &Path, source_hash: &str, config_hash: &str, op: Operation) -> Option<Vec<LintMessage>> {
+        let entry = self.entries.get(path)?;
+        if entry.source_hash != source_hash || entry.config_hash != config_hash || entry.op != op {
+            return None;
+        }
+        let file = path.to_string_lossy().into_owned();
+        Some(
+            entry
+                .messages
+                .iter()
+                .cloned()
+                .map(|m| m.into_lint_message(&file))
+                .collect(),
+        )
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        path: PathBuf,
+        source_hash: String,
+        config_hash: String,
+        op: Operation,
+        messages: &[LintMessage],
+    ) {
+        self.entries.insert(
+            path,
+            CacheEntry {
+                source_hash,
+                config_hash,
+                op,
+                messages: messages.iter().map(CachedMessage::from).collect(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_message() -> LintMessage {
+        LintMessage {
+            file: String::new(),
+            line: 1,
+            col: 0,
+            end_col: 1,
+            rule: "ambiguous-name",
+            message: "Ambiguous variable name 'l'.".to_string(),
+        }
+    }
+
+    #[test]
+    fn get_hits_on_matching_hashes_and_op() {
+        let mut cache = Cache::empty();
+        let path = PathBuf::from("a.py");
+        cache.insert(path.clone(), "src".into(), "cfg".into(), Operation::Check, &[sample_message()]);
+
+        let hit = cache.get(&path, "src", "cfg", Operation::Check);
+        assert_eq!(hit.map(|m| m.len()), Some(1));
+    }
+
+    #[test]
+    fn get_misses_on_source_or_config_change() {
+        let mut cache = Cache::empty();
+        let path = PathBuf::from("a.py");
+        cache.insert(path.clone(), "src".into(), "cfg".into(), Operation::Check, &[sample_message()]);
+
+        assert!(cache.get(&path, "other-src", "cfg", Operation::Check).is_none());
+        assert!(cache.get(&path, "src", "other-cfg", Operation::Check).is_none());
+    }
+
+    #[test]
+    fn get_misses_when_op_does_not_match_writer() {
+        let mut cache = Cache::empty();
+        let path = PathBuf::from("a.py");
+        cache.insert(path.clone(), "src".into(), "cfg".into(), Operation::Check, &[sample_message()]);
+
+        // A `check`-written entry must never satisfy a `fix` lookup: `check`
+        // never ran find_fixes/wrote a fix, so `fix` can't trust it.
+        assert!(cache.get(&path, "src", "cfg", Operation::Fix).is_none());
+    }
+
+    #[test]
+    fn config_hash_is_stable_for_equal_configs() {
+        let a = LintConfig::default();
+        let b = LintConfig::default();
+        assert_eq!(config_hash(&a), config_hash(&b));
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use dashmap::DashMap;
+use log::info;
+use tower_lsp::jsonrpc::Result as LspResult;
+use tower_lsp::lsp_types::*;
+use tower_lsp::{Client, LanguageServer, LspService, Server};
+
+use crate::{find_fixes, line_col_to_byte, load_config, parse_code, run_linter, Fix, LintConfig};
+
+/// Runs fastpy as a long-lived LSP server over stdin/stdout.
+pub async fn run() {
+    let (service, socket) = LspService::new(|client| Backend {
+        client,
+        documents: DashMap::new(),
+    });
+    let stdin = tokio::io::stdin();
+    let stdout = tokio::io::stdout();
+    Server::new(stdin, stdout, socket).serve(service).await;
+}
+
+struct Backend {
+    client: Client,
+    /// In-memory document text keyed by URI, kept in sync via
+    /// `didOpen`/`didChange` so we never have to re-read from disk.
+    documents: DashMap<Url, String>,
+}
+
+impl Backend {
+    async fn publish_diagnostics(&self, uri: Url, text: &str) {
+        let config = workspace_config(&uri);
+        let Some(tree) = parse_code(text) else {
+            return;
+        };
+
+        let diagnostics = run_linter(&tree, text, &config)
+            .into_iter()
+            .filter_map(|m| {
+                // `m.col`/`m.end_col` are tree-sitter's UTF-8 byte columns,
+                // but LSP positions are UTF-16 code units; go through a
+                // byte offset and `byte_to_position` (same conversion
+                // `code_action` uses) rather than passing them through
+                // unconverted.
+                let start = line_col_to_byte(text, m.line, m.col)?;
+                let end = line_col_to_byte(text, m.line, m.end_col)?;
+                Some(Diagnostic {
+                    range: Range::new(byte_to_position(text, start), byte_to_position(text, end)),
+                    severity: Some(DiagnosticSeverity::WARNING),
+                    code: Some(NumberOrString::String(m.rule.to_string())),
+                    source: Some("fastpy".to_string()),
+                    message: m.message,
+                    ..Default::default()
+                })
+            })
+            .collect();
+
+        self.client.publish_diagnostics(uri, diagnostics, None).await;
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, _: InitializeParams) -> LspResult<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Kind(
+                    TextDocumentSyncKind::FULL,
+                )),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    async fn initialized(&self, _: InitializedParams) {
+        info!("fastpy language server initialized");
+    }
+
+    async fn shutdown(&self) -> LspResult<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let uri = params.text_document.uri;
+        let text = params.text_document.text;
+        self.publish_diagnostics(uri.clone(), &text).await;
+        self.documents.insert(uri, text);
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let uri = params.text_document.uri;
+        // We advertise FULL sync, so the last (and only) content change
+        // carries the whole document.
+        let Some(change) = params.content_changes.into_iter().next_back() else {
+            return;
+        };
+        self.publish_diagnostics(uri.clone(), &change.text).await;
+        self.documents.insert(uri, change.text);
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.documents.remove(&params.text_document.uri);
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> LspResult<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        let Some(text) = self.documents.get(&uri) else {
+            return Ok(None);
+        };
+        let Some(tree) = parse_code(&text) else {
+            return Ok(None);
+        };
+        let config = workspace_config(&uri);
+
+        let actions = find_fixes(&tree, &text, &config)
+            .into_iter()
+            .map(|fix| CodeActionOrCommand::CodeAction(fix_to_code_action(&uri, &text, &fix)))
+            .collect();
+        Ok(Some(actions))
+    }
+}
+
+fn fix_to_code_action(uri: &Url, text: &str, fix: &Fix) -> CodeAction {
+    let edit = TextEdit {
+        range: Range::new(byte_to_position(text, fix.start), byte_to_position(text, fix.end)),
+        new_text: fix.replacement.clone(),
+    };
+    let mut changes = HashMap::new();
+    changes.insert(uri.clone(), vec![edit]);
+
+    CodeAction {
+        title: format!("Replace with `{}`", fix.replacement),
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Converts a byte offset into `text` to an LSP `Position` (0-based line
+/// and UTF-16 code unit column).
+fn byte_to_position(text: &str, byte: usize) -> Position {
+    let mut line = 0u32;
+    let mut line_start = 0;
+    for (idx, ch) in text.char_indices() {
+        if idx >= byte {
+            break;
+        }
+        if ch == '\n' {
+            line += 1;
+            line_start = idx + 1;
+        }
+    }
+    let character = text[line_start..byte].encode_utf16().count() as u32;
+    Position::new(line, character)
+}
+
+/// Loads `.fastpy.toml` for the workspace root containing `uri`, falling
+/// back to defaults when there is none.
+fn workspace_config(uri: &Url) -> LintConfig {
+    uri.to_file_path()
+        .ok()
+        .and_then(|path| load_config(&path))
+        .unwrap_or_default()
+}
@@ -1,31 +1,109 @@
 use log::{info, warn, error, debug};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
+use similar::{ChangeTag, TextDiff};
 use std::fs;
+use std::io::Read as _;
 use std::path::{Path, PathBuf};
 use tree_sitter::{Parser as TSParser, Tree};
 use walkdir::WalkDir;
 use itertools::Itertools;
+use rayon::prelude::*;
 use tree_sitter_python::language as tree_sitter_python;
 
+mod cache;
+mod lsp;
+mod report;
+mod rules;
+
+use cache::Cache;
+use report::{build_reporter, Format};
+
+/// Maximum number of find-fixes/apply-fixes rounds before giving up, to
+/// guard against fixes that oscillate instead of converging.
+const MAX_FIX_PASSES: usize = 8;
+
 #[derive(Parser, Debug)]
 #[command(name = "fastpy")]
 #[command(about = "An extremely fast Python linter and formatter written in Rust", long_about = None)]
-struct Args {
-    #[arg(short, long)]
-    file: PathBuf,
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Lint files or a directory and report diagnostics; never writes.
+    ///
+    /// `target` may be `-` to read an unsaved buffer from stdin instead
+    /// of a real path.
+    Check {
+        /// File or directory to lint, or `-` to read from stdin.
+        target: PathBuf,
+
+        #[arg(long, value_enum, default_value_t = Format::Compact)]
+        format: Format,
+
+        /// Logical path to resolve `.fastpy.toml` against and report
+        /// diagnostics under when `target` is `-`.
+        #[arg(long)]
+        stdin_filename: Option<PathBuf>,
+    },
+    /// Apply all available auto-fixes across files or a directory.
+    ///
+    /// `target` may be `-` to read an unsaved buffer from stdin, in which
+    /// case the fixed result is printed to stdout instead of being
+    /// written to disk.
+    Fix {
+        /// File or directory to fix, or `-` to read from stdin.
+        target: PathBuf,
+
+        /// Print a unified diff of the fixes instead of writing them to disk.
+        #[arg(long)]
+        diff: bool,
 
-    #[arg(long)]
-    fix: bool,
+        #[arg(long, value_enum, default_value_t = Format::Compact)]
+        format: Format,
 
-    #[arg(long)]
-    json: bool,
+        /// Logical path to resolve `.fastpy.toml` against and report
+        /// diagnostics under when `target` is `-`.
+        #[arg(long)]
+        stdin_filename: Option<PathBuf>,
+    },
+    /// Apply exactly the one fix whose byte range covers a given position.
+    ///
+    /// Intended for editor integrations that want to resolve a single
+    /// quick-fix rather than rewriting the whole file.
+    FixSingle {
+        /// File or directory to search for the matching fix.
+        target: PathBuf,
+
+        /// 1-based line number of the position to fix.
+        #[arg(long)]
+        line: usize,
+
+        /// 0-based column of the position to fix.
+        #[arg(long)]
+        col: usize,
+    },
+    /// Print a longer description of a diagnostic rule.
+    Explain {
+        /// The rule id shown alongside a diagnostic, e.g. `ambiguous-name`.
+        rule_id: String,
+    },
+    /// Run a long-lived LSP server over stdin/stdout for editor integrations.
+    Lsp,
 }
 
 #[derive(Serialize)]
 struct LintMessage {
     file: String,
     line: usize,
+    /// 0-based column the diagnostic starts at, for reporters that render
+    /// a caret under the offending span (e.g. the `pretty` reporter).
+    col: usize,
+    end_col: usize,
+    rule: &'static str,
     message: String,
 }
 
@@ -36,11 +114,14 @@ struct Fix {
     replacement: String,
 }
 
-#[derive(Deserialize, Default)]
+#[derive(Deserialize, Serialize, Default)]
 struct LintConfig {
-    disallow_ambiguous_names: Option<bool>,
-    #[allow(dead_code)]
     max_line_length: Option<usize>,
+    /// Rule ids to run; defaults to every registered rule when unset.
+    select: Option<Vec<String>>,
+    /// Rule ids to skip even if selected, e.g. to keep most defaults but
+    /// turn off one noisy rule.
+    ignore: Option<Vec<String>>,
 }
 
 fn load_config(path: &Path) -> Option<LintConfig> {
@@ -57,49 +138,30 @@ fn parse_code(code: &str) -> Option<Tree> {
 }
 
 fn run_linter(tree: &Tree, source_code: &str, config: &LintConfig) -> Vec<LintMessage> {
-    let mut messages = Vec::new();
-    messages.extend(lint_ambiguous_names(tree, source_code, config));
-    messages
-}
-
-fn lint_ambiguous_names(tree: &Tree, source_code: &str, config: &LintConfig) -> Vec<LintMessage> {
-    let mut messages = Vec::new();
-    let root = tree.root_node();
-    let mut cursor = root.walk();
-
-    for node in root.children(&mut cursor) {
-        if node.kind() == "assignment" {
-            if let Some(left_node) = node.child_by_field_name("left") {
-                let name = left_node.utf8_text(source_code.as_bytes()).unwrap_or("");
-                if config.disallow_ambiguous_names.unwrap_or(true) && (name == "l" || name == "O") {
-                    messages.push(LintMessage {
-                        file: "".into(),
-                        line: left_node.start_position().row + 1,
-                        message: format!("Ambiguous variable name '{}'.", name),
-                    });
-                }
-            }
-        }
-    }
-
-    messages
+    rules::run_all(tree, source_code, config)
 }
 
-fn find_fixes(tree: &Tree, source_code: &str) -> Vec<Fix> {
+/// Only emits fixes for rules enabled by `config.select`/`config.ignore`,
+/// the same gate `rules::run_all` applies to diagnostics, so a rule
+/// disabled for reporting doesn't still get auto-fixed.
+fn find_fixes(tree: &Tree, source_code: &str, config: &LintConfig) -> Vec<Fix> {
     let mut fixes = Vec::new();
-    let root = tree.root_node();
-    let mut cursor = root.walk();
-
-    for node in root.children(&mut cursor) {
-        if node.kind() == "assignment" {
-            if let Some(left_node) = node.child_by_field_name("left") {
-                let name = left_node.utf8_text(source_code.as_bytes()).unwrap_or("");
-                if name == "l" {
-                    fixes.push(Fix {
-                        start: left_node.start_byte(),
-                        end: left_node.end_byte(),
-                        replacement: "line".to_string(),
-                    });
+
+    if rules::is_enabled("ambiguous-name", config) {
+        let root = tree.root_node();
+        let mut cursor = root.walk();
+
+        for node in root.children(&mut cursor) {
+            if node.kind() == "assignment" {
+                if let Some(left_node) = node.child_by_field_name("left") {
+                    let name = left_node.utf8_text(source_code.as_bytes()).unwrap_or("");
+                    if name == "l" {
+                        fixes.push(Fix {
+                            start: left_node.start_byte(),
+                            end: left_node.end_byte(),
+                            replacement: "line".to_string(),
+                        });
+                    }
                 }
             }
         }
@@ -112,6 +174,11 @@ fn apply_fixes(code: &str, fixes: Vec<Fix>) -> String {
     let mut result = String::new();
     let mut last = 0;
     for fix in fixes.iter().sorted_by_key(|f| f.start) {
+        if fix.start < last {
+            // Overlaps a fix already accepted in this pass; leave it alone
+            // and let the next pass pick it up after a reparse.
+            continue;
+        }
         result.push_str(&code[last..fix.start]);
         result.push_str(&fix.replacement);
         last = fix.end;
@@ -120,6 +187,107 @@ fn apply_fixes(code: &str, fixes: Vec<Fix>) -> String {
     result
 }
 
+/// Repeatedly reparses `code`, re-runs `find_fixes`, and applies whatever
+/// fixes come back, since a fix can shift byte offsets or expose lints
+/// that were hidden behind the code it just rewrote. Each pass must
+/// strictly reduce the number of outstanding lints or we stop, both to
+/// avoid masking a bug in a rule and to guarantee termination even before
+/// `MAX_FIX_PASSES` is hit.
+fn apply_fixes_multipass(path: &Path, mut code: String, config: &LintConfig) -> String {
+    let mut previous_count = usize::MAX;
+    for pass in 1..=MAX_FIX_PASSES {
+        let Some(tree) = parse_code(&code) else {
+            warn!("{:?}: stopped fixing after a pass produced unparseable code", path);
+            break;
+        };
+
+        let fixes = find_fixes(&tree, &code, config);
+        if fixes.is_empty() {
+            debug!("{:?}: no more fixes after {} pass(es)", path, pass - 1);
+            break;
+        }
+
+        code = apply_fixes(&code, fixes);
+
+        let Some(retree) = parse_code(&code) else {
+            warn!("{:?}: stopped fixing after a pass produced unparseable code", path);
+            break;
+        };
+        let remaining = run_linter(&retree, &code, config).len();
+        debug!("{:?}: pass {} left {} lint(s)", path, pass, remaining);
+        if remaining >= previous_count {
+            warn!(
+                "{:?}: fix pass {} made no progress ({} lint(s) remaining); stopping",
+                path, pass, remaining
+            );
+            break;
+        }
+        previous_count = remaining;
+    }
+    code
+}
+
+/// Renders a line-based unified diff between the original and fixed source
+/// for `path`, with a few lines of surrounding context. Returned as a
+/// string rather than printed directly so callers running in parallel can
+/// buffer it and print in deterministic, path-sorted order.
+fn build_fix_diff(path: &Path, original: &str, fixed: &str) -> String {
+    use std::fmt::Write;
+
+    let diff = TextDiff::from_lines(original, fixed);
+    let mut out = String::new();
+    let _ = writeln!(out, "--- {}", path.display());
+    let _ = writeln!(out, "+++ {}", path.display());
+    for hunk in diff.unified_diff().context_radius(3).iter_hunks() {
+        let _ = write!(out, "{}", hunk.header());
+        for change in hunk.iter_changes() {
+            let sign = match change.tag() {
+                ChangeTag::Delete => "-",
+                ChangeTag::Insert => "+",
+                ChangeTag::Equal => " ",
+            };
+            let _ = write!(out, "{}{}", sign, change);
+        }
+    }
+    out
+}
+
+/// Returns the byte offset of a 1-based line / 0-based column position in
+/// `source`, or `None` if the position is past the end of the file.
+pub(crate) fn line_col_to_byte(source: &str, line: usize, col: usize) -> Option<usize> {
+    let mut offset = 0;
+    for (idx, text_line) in source.split_inclusive('\n').enumerate() {
+        if idx + 1 == line {
+            return Some(offset + col);
+        }
+        offset += text_line.len();
+    }
+    None
+}
+
+/// Prints a longer, human-readable explanation of a diagnostic rule.
+fn explain_rule(rule_id: &str) {
+    let description = match rule_id {
+        "ambiguous-name" => {
+            "ambiguous-name: flags variables named `l` or `O`, which are easily \
+             confused with the digits `1` and `0` in many fonts. Rename them to \
+             something descriptive; `fastpy fix` rewrites `l` to `line` \
+             automatically."
+        }
+        "max-line-length" => {
+            "max-line-length: flags lines longer than the `max_line_length` \
+             configured in `.fastpy.toml` (counting characters, not bytes). \
+             Disabled unless `max_line_length` is set, since there's no \
+             sensible default line width to enforce."
+        }
+        _ => {
+            println!("Unknown rule '{}'. See the rule id printed alongside a diagnostic.", rule_id);
+            return;
+        }
+    };
+    println!("{}", description);
+}
+
 fn find_python_files(dir: &Path) -> Vec<PathBuf> {
     WalkDir::new(dir)
         .into_iter()
@@ -129,54 +297,341 @@ fn find_python_files(dir: &Path) -> Vec<PathBuf> {
         .collect()
 }
 
-fn main() {
-    env_logger::init();
-    let args = Args::parse();
+fn target_files(target: &Path) -> Vec<PathBuf> {
+    if target.is_dir() {
+        find_python_files(target)
+    } else {
+        vec![target.to_path_buf()]
+    }
+}
+
+/// The `target` value that means "read the document from stdin".
+const STDIN_TARGET: &str = "-";
+
+/// Reads the full document to lint/fix from stdin, as used for
+/// editor "format/lint on type" integrations that work on an unsaved
+/// buffer rather than a file on disk.
+fn read_stdin() -> String {
+    let mut code = String::new();
+    std::io::stdin()
+        .read_to_string(&mut code)
+        .expect("Unable to read stdin");
+    code
+}
 
-    let paths = if args.file.is_dir() {
-        find_python_files(&args.file)
+struct CheckResult {
+    path: PathBuf,
+    cache_key: PathBuf,
+    source_hash: String,
+    config_hash: String,
+    source: String,
+    messages: Vec<LintMessage>,
+}
+
+/// Lints a single file, reusing the incremental cache when the file's
+/// contents and the effective config haven't changed since last time.
+/// Returns `None` (after logging) if the file fails to parse, so a bad
+/// file in a large directory run doesn't stop the rest.
+fn check_one_file(path: &Path, cache: &Cache) -> Option<CheckResult> {
+    let code = fs::read_to_string(path).expect("Unable to read file");
+    let config = load_config(path).unwrap_or_default();
+    let cache_key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let source_hash = cache::hash_bytes(code.as_bytes());
+    let config_hash = cache::config_hash(&config);
+    let file = path.to_string_lossy().into_owned();
+
+    let messages = if let Some(cached) = cache.get(&cache_key, &source_hash, &config_hash, cache::Operation::Check) {
+        debug!("{:?}: unchanged since last run, reusing cached diagnostics", path);
+        cached
     } else {
-        vec![args.file.clone()]
+        info!("\n📂 Processing {:?}", path);
+        // tree-sitter's `Parser` isn't `Sync`, so `parse_code` builds a
+        // fresh one per call; running one call per rayon task keeps every
+        // parser thread-local without us having to manage a pool ourselves.
+        let tree = parse_code(&code).or_else(|| {
+            error!("❌ Failed to parse {:?}", path);
+            None
+        })?;
+        run_linter(&tree, &code, &config)
     };
+    let messages = messages
+        .into_iter()
+        .map(|mut m| {
+            m.file = file.clone();
+            m
+        })
+        .collect();
 
-    for path in paths {
-        info!("\n📂 Processing {:?}", path);
+    Some(CheckResult { path: path.to_path_buf(), cache_key, source_hash, config_hash, source: code, messages })
+}
 
-        let code = fs::read_to_string(&path).expect("Unable to read file");
-        let config = load_config(&path).unwrap_or_default();
-
-        if let Some(tree) = parse_code(&code) {
-            let messages = run_linter(&tree, &code, &config);
-            if args.json {
-                let messages_json: Vec<LintMessage> = messages
-                    .into_iter()
-                    .map(|mut m| {
-                        m.file = path.to_string_lossy().into();
-                        m
-                    })
-                    .collect();
-                println!("{}", serde_json::to_string_pretty(&messages_json).unwrap());
-            } else {
-                for msg in &messages {
-                    println!("[line {}] {}", msg.line, msg.message);
-                }
-            }
+fn run_check(target: &Path, format: Format, stdin_filename: Option<&Path>) {
+    if target == Path::new(STDIN_TARGET) {
+        let code = read_stdin();
+        let display_path = stdin_filename.unwrap_or_else(|| Path::new("<stdin>"));
+        let config = stdin_filename.and_then(load_config).unwrap_or_default();
 
-            let fixes = find_fixes(&tree, &code);
-            if !fixes.is_empty() {
-                info!("\n✏️ Auto-fixes available:");
-                for fix in &fixes {
-                    info!("{:?}", fix);
-                }
+        let Some(tree) = parse_code(&code) else {
+            error!("❌ Failed to parse <stdin>");
+            return;
+        };
+        let messages: Vec<LintMessage> = run_linter(&tree, &code, &config)
+            .into_iter()
+            .map(|mut m| {
+                m.file = display_path.to_string_lossy().into();
+                m
+            })
+            .collect();
 
-                let fixed_code = apply_fixes(&code, fixes);
-                if args.fix {
-                    fs::write(&path, &fixed_code).expect("Failed to write fixed file");
-                    info!("✅ Auto-fixed and saved");
-                }
-            }
+        build_reporter(format).report(display_path, &code, &messages);
+        return;
+    }
+
+    let cache = Cache::load();
+    let mut results: Vec<CheckResult> = target_files(target)
+        .par_iter()
+        .filter_map(|path| check_one_file(path, &cache))
+        .collect();
+    // Diagnostics must come out in a stable, scheduling-independent order.
+    results.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut new_cache = Cache::empty();
+    for result in &results {
+        new_cache.insert(
+            result.cache_key.clone(),
+            result.source_hash.clone(),
+            result.config_hash.clone(),
+            cache::Operation::Check,
+            &result.messages,
+        );
+    }
+    new_cache.save();
+
+    let reporter = build_reporter(format);
+    for result in results {
+        reporter.report(&result.path, &result.source, &result.messages);
+    }
+}
+
+struct FixOutcome {
+    path: PathBuf,
+    cache_key: PathBuf,
+    source_hash: String,
+    config_hash: String,
+    messages: Vec<LintMessage>,
+    source: String,
+    diff: Option<String>,
+    /// True once we've written a fix to disk, which changes the file's
+    /// hash; such files must not be cached under the hash we just read.
+    modified: bool,
+}
+
+fn fix_one_file(path: &Path, diff: bool, cache: &Cache) -> Option<FixOutcome> {
+    let code = fs::read_to_string(path).expect("Unable to read file");
+    let config = load_config(path).unwrap_or_default();
+    let cache_key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let source_hash = cache::hash_bytes(code.as_bytes());
+    let config_hash = cache::config_hash(&config);
+    let file = path.to_string_lossy().into_owned();
+
+    let finish = |messages: Vec<LintMessage>, diff: Option<String>, modified: bool| {
+        let messages = messages
+            .into_iter()
+            .map(|mut m| {
+                m.file = file.clone();
+                m
+            })
+            .collect();
+        Some(FixOutcome {
+            path: path.to_path_buf(),
+            cache_key: cache_key.clone(),
+            source_hash: source_hash.clone(),
+            config_hash: config_hash.clone(),
+            source: code.clone(),
+            messages,
+            diff,
+            modified,
+        })
+    };
+
+    if let Some(messages) = cache.get(&cache_key, &source_hash, &config_hash, cache::Operation::Fix) {
+        debug!("{:?}: unchanged since last run, skipping fixes", path);
+        return finish(messages, None, false);
+    }
+
+    info!("\n📂 Processing {:?}", path);
+    let tree = parse_code(&code).or_else(|| {
+        error!("❌ Failed to parse {:?}", path);
+        None
+    })?;
+    let messages = run_linter(&tree, &code, &config);
+
+    let fixes = find_fixes(&tree, &code, &config);
+    if fixes.is_empty() {
+        return finish(messages, None, false);
+    }
+    info!("\n✏️ Auto-fixes available:");
+    for fix in &fixes {
+        info!("{:?}", fix);
+    }
+
+    let fixed_code = apply_fixes_multipass(path, code.clone(), &config);
+    if diff {
+        finish(messages, Some(build_fix_diff(path, &code, &fixed_code)), false)
+    } else {
+        fs::write(path, &fixed_code).expect("Failed to write fixed file");
+        info!("✅ Auto-fixed and saved");
+        finish(messages, None, true)
+    }
+}
+
+fn run_fix(target: &Path, diff: bool, format: Format, stdin_filename: Option<&Path>) {
+    if target == Path::new(STDIN_TARGET) {
+        let code = read_stdin();
+        let display_path = stdin_filename.unwrap_or_else(|| Path::new("<stdin>"));
+        let config = stdin_filename.and_then(load_config).unwrap_or_default();
+
+        let Some(tree) = parse_code(&code) else {
+            error!("❌ Failed to parse <stdin>");
+            return;
+        };
+        let messages: Vec<LintMessage> = run_linter(&tree, &code, &config)
+            .into_iter()
+            .map(|mut m| {
+                m.file = display_path.to_string_lossy().into();
+                m
+            })
+            .collect();
+        // The format-on-save integration contract requires stdout to
+        // contain *only* the transformed buffer, or an editor piping this
+        // back into the file gets diagnostic text spliced into the
+        // source. `format`/the reporter don't apply here; any remaining
+        // diagnostics go to stderr instead.
+        for m in &messages {
+            eprintln!("[line {}] {} ({})", m.line, m.message, m.rule);
+        }
+
+        let fixed_code = if find_fixes(&tree, &code, &config).is_empty() {
+            code
         } else {
-            error!("❌ Failed to parse {:?}", path);
+            apply_fixes_multipass(display_path, code, &config)
+        };
+        print!("{}", fixed_code);
+        return;
+    }
+
+    let cache = Cache::load();
+    let mut outcomes: Vec<FixOutcome> = target_files(target)
+        .par_iter()
+        .filter_map(|path| fix_one_file(path, diff, &cache))
+        .collect();
+    outcomes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut new_cache = Cache::empty();
+    for outcome in &outcomes {
+        // A `--diff` preview that found fixes but never wrote them must
+        // not be cached as "nothing to fix": the next run (with or
+        // without `--diff`) needs to see those fixes again, not silently
+        // skip them because the content hash hasn't changed.
+        if !outcome.modified && outcome.diff.is_none() {
+            new_cache.insert(
+                outcome.cache_key.clone(),
+                outcome.source_hash.clone(),
+                outcome.config_hash.clone(),
+                cache::Operation::Fix,
+                &outcome.messages,
+            );
+        }
+    }
+    new_cache.save();
+
+    let reporter = build_reporter(format);
+    for outcome in outcomes {
+        reporter.report(&outcome.path, &outcome.source, &outcome.messages);
+        if let Some(diff_text) = outcome.diff {
+            print!("{}", diff_text);
+        }
+    }
+}
+
+/// `line`/`col` only make sense relative to one specific file, so unlike
+/// `check`/`fix`, `target` must be that file directly; silently falling
+/// back to a directory search (as `target_files` does) could apply the
+/// fix to the wrong file if another one under it coincidentally has a
+/// matching node at that position.
+fn run_fix_single(target: &Path, line: usize, col: usize) {
+    if target.is_dir() {
+        error!("fix-single requires a file path, got a directory: {:?}", target);
+        return;
+    }
+
+    let code = fs::read_to_string(target).expect("Unable to read file");
+    let config = load_config(target).unwrap_or_default();
+    let Some(tree) = parse_code(&code) else {
+        error!("❌ Failed to parse {:?}", target);
+        return;
+    };
+
+    let Some(byte) = line_col_to_byte(&code, line, col) else {
+        warn!("No fix found covering line {} col {}", line, col);
+        return;
+    };
+    let fixes = find_fixes(&tree, &code, &config);
+    let Some(fix) = fixes.into_iter().find(|f| f.start <= byte && byte < f.end) else {
+        warn!("No fix found covering line {} col {}", line, col);
+        return;
+    };
+
+    info!("\n📂 Fixing {:?}:{}:{}", target, line, col);
+    let fixed_code = apply_fixes(&code, vec![fix]);
+    fs::write(target, &fixed_code).expect("Failed to write fixed file");
+    info!("✅ Auto-fixed and saved");
+}
+
+fn main() {
+    env_logger::init();
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Check { target, format, stdin_filename } => {
+            run_check(&target, format, stdin_filename.as_deref())
         }
+        Command::Fix { target, diff, format, stdin_filename } => {
+            run_fix(&target, diff, format, stdin_filename.as_deref())
+        }
+        Command::FixSingle { target, line, col } => run_fix_single(&target, line, col),
+        Command::Explain { rule_id } => explain_rule(&rule_id),
+        Command::Lsp => tokio::runtime::Runtime::new()
+            .expect("Failed to start async runtime")
+            .block_on(lsp::run()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_fixes_multipass_renames_ambiguous_names() {
+        let config = LintConfig::default();
+        let code = "l = 1\nx = 2\n".to_string();
+        let fixed = apply_fixes_multipass(Path::new("test.py"), code, &config);
+        assert_eq!(fixed, "line = 1\nx = 2\n");
+    }
+
+    #[test]
+    fn apply_fixes_multipass_is_a_fixpoint() {
+        let config = LintConfig::default();
+        let once = apply_fixes_multipass(Path::new("test.py"), "l = 1\n".to_string(), &config);
+        let twice = apply_fixes_multipass(Path::new("test.py"), once.clone(), &config);
+        assert_eq!(once, twice);
+    }
+
+    #[test]
+    fn apply_fixes_multipass_noop_without_fixes() {
+        let config = LintConfig::default();
+        let code = "x = 1\n".to_string();
+        let fixed = apply_fixes_multipass(Path::new("test.py"), code.clone(), &config);
+        assert_eq!(fixed, code);
     }
 }
@@ -0,0 +1,95 @@
+use std::path::Path;
+
+use clap::ValueEnum;
+
+use crate::LintMessage;
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub(crate) enum Format {
+    /// One short line per diagnostic.
+    Compact,
+    /// The offending source line with a caret under the diagnostic span.
+    Pretty,
+    /// A JSON array of diagnostics, one array per file.
+    Json,
+    /// GitHub Actions `::warning` workflow commands, for CI annotations.
+    Github,
+}
+
+/// Renders diagnostics for a single file. Implementations must not assume
+/// files are reported in any particular order relative to each other, but
+/// a single call always covers one file's diagnostics together.
+pub(crate) trait Reporter {
+    fn report(&self, path: &Path, source: &str, messages: &[LintMessage]);
+}
+
+pub(crate) fn build_reporter(format: Format) -> Box<dyn Reporter> {
+    match format {
+        Format::Compact => Box::new(CompactReporter),
+        Format::Pretty => Box::new(PrettyReporter),
+        Format::Json => Box::new(JsonReporter),
+        Format::Github => Box::new(GithubReporter),
+    }
+}
+
+struct CompactReporter;
+
+impl Reporter for CompactReporter {
+    fn report(&self, _path: &Path, _source: &str, messages: &[LintMessage]) {
+        for m in messages {
+            println!("[line {}] {} ({})", m.line, m.message, m.rule);
+        }
+    }
+}
+
+struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn report(&self, _path: &Path, _source: &str, messages: &[LintMessage]) {
+        println!("{}", serde_json::to_string_pretty(messages).unwrap());
+    }
+}
+
+struct GithubReporter;
+
+impl Reporter for GithubReporter {
+    fn report(&self, path: &Path, _source: &str, messages: &[LintMessage]) {
+        for m in messages {
+            println!(
+                "::warning file={},line={}::{}",
+                escape_property(&path.display().to_string()),
+                m.line,
+                escape_data(&m.message)
+            );
+        }
+    }
+}
+
+/// Escapes a workflow command's message text per GitHub's encoding rules.
+/// Without this, a `%`, `\r`, or `\n` in the message corrupts or
+/// truncates the annotation instead of rendering as one warning.
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes a workflow command property value (e.g. `file=`), which also
+/// needs `:` and `,` encoded since those delimit properties.
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+struct PrettyReporter;
+
+impl Reporter for PrettyReporter {
+    fn report(&self, path: &Path, source: &str, messages: &[LintMessage]) {
+        for m in messages {
+            println!("{}:{}:{}: {} [{}]", path.display(), m.line, m.col + 1, m.message, m.rule);
+            let Some(line_text) = source.lines().nth(m.line - 1) else {
+                continue;
+            };
+            println!("  {}", line_text);
+            let width = m.end_col.saturating_sub(m.col).max(1);
+            println!("  {}{}", " ".repeat(m.col), "^".repeat(width));
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use tree_sitter::Tree;
+
+use crate::{LintConfig, LintMessage};
+
+/// A single diagnostic check. Rules are independent of each other and of
+/// the auto-fixer: a rule only has to find problems, not know how to fix
+/// them.
+pub(crate) trait Rule {
+    /// Stable identifier shown alongside diagnostics and used by
+    /// `select`/`ignore` in `.fastpy.toml` and by `fastpy explain`.
+    fn id(&self) -> &'static str;
+
+    fn check(&self, tree: &Tree, source: &str, config: &LintConfig) -> Vec<LintMessage>;
+}
+
+struct AmbiguousNameRule;
+
+impl Rule for AmbiguousNameRule {
+    fn id(&self) -> &'static str {
+        "ambiguous-name"
+    }
+
+    fn check(&self, tree: &Tree, source: &str, _config: &LintConfig) -> Vec<LintMessage> {
+        let mut messages = Vec::new();
+        let root = tree.root_node();
+        let mut cursor = root.walk();
+
+        for node in root.children(&mut cursor) {
+            if node.kind() == "assignment" {
+                if let Some(left_node) = node.child_by_field_name("left") {
+                    let name = left_node.utf8_text(source.as_bytes()).unwrap_or("");
+                    if name == "l" || name == "O" {
+                        messages.push(LintMessage {
+                            file: "".into(),
+                            line: left_node.start_position().row + 1,
+                            col: left_node.start_position().column,
+                            end_col: left_node.end_position().column,
+                            rule: self.id(),
+                            message: format!("Ambiguous variable name '{}'.", name),
+                        });
+                    }
+                }
+            }
+        }
+
+        messages
+    }
+}
+
+struct MaxLineLengthRule;
+
+impl Rule for MaxLineLengthRule {
+    fn id(&self) -> &'static str {
+        "max-line-length"
+    }
+
+    fn check(&self, _tree: &Tree, source: &str, config: &LintConfig) -> Vec<LintMessage> {
+        // There's no sane universal default line width, so this rule is a
+        // no-op until the user opts in via config.
+        let Some(limit) = config.max_line_length else {
+            return Vec::new();
+        };
+
+        let mut messages = Vec::new();
+        for (idx, line) in source.lines().enumerate() {
+            let len = line.chars().count();
+            if len > limit {
+                messages.push(LintMessage {
+                    file: "".into(),
+                    line: idx + 1,
+                    col: limit,
+                    end_col: len,
+                    rule: self.id(),
+                    message: format!("Line too long ({} > {} characters).", len, limit),
+                });
+            }
+        }
+
+        messages
+    }
+}
+
+/// All registered rules, in a stable order so diagnostics from multiple
+/// rules on the same file come out deterministically.
+fn all_rules() -> Vec<Box<dyn Rule>> {
+    vec![Box::new(AmbiguousNameRule), Box::new(MaxLineLengthRule)]
+}
+
+/// Whether `rule_id` runs under `config.select`/`config.ignore` (all rules
+/// run by default; `ignore` always wins over `select`). Shared by
+/// `run_all` and by `find_fixes` in `main.rs`, so a rule disabled for
+/// reporting is also disabled for auto-fixing.
+pub(crate) fn is_enabled(rule_id: &str, config: &LintConfig) -> bool {
+    if let Some(ignore) = &config.ignore {
+        if ignore.iter().any(|id| id == rule_id) {
+            return false;
+        }
+    }
+    match &config.select {
+        Some(select) => select.iter().any(|id| id == rule_id),
+        None => true,
+    }
+}
+
+/// Runs every rule enabled by `config.select`/`config.ignore` (all rules
+/// run by default) against `tree`/`source`.
+pub(crate) fn run_all(tree: &Tree, source: &str, config: &LintConfig) -> Vec<LintMessage> {
+    all_rules()
+        .into_iter()
+        .filter(|rule| is_enabled(rule.id(), config))
+        .flat_map(|rule| rule.check(tree, source, config))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enabled_by_default_with_no_select_or_ignore() {
+        let config = LintConfig::default();
+        assert!(is_enabled("ambiguous-name", &config));
+        assert!(is_enabled("max-line-length", &config));
+    }
+
+    #[test]
+    fn empty_select_disables_everything() {
+        let config = LintConfig {
+            select: Some(Vec::new()),
+            ..LintConfig::default()
+        };
+        assert!(!is_enabled("ambiguous-name", &config));
+    }
+
+    #[test]
+    fn select_only_enables_listed_rules() {
+        let config = LintConfig {
+            select: Some(vec!["ambiguous-name".to_string()]),
+            ..LintConfig::default()
+        };
+        assert!(is_enabled("ambiguous-name", &config));
+        assert!(!is_enabled("max-line-length", &config));
+    }
+
+    #[test]
+    fn ignore_beats_select() {
+        let config = LintConfig {
+            select: Some(vec!["ambiguous-name".to_string()]),
+            ignore: Some(vec!["ambiguous-name".to_string()]),
+            ..LintConfig::default()
+        };
+        assert!(!is_enabled("ambiguous-name", &config));
+    }
+
+    #[test]
+    fn ignore_without_select_only_disables_listed_rule() {
+        let config = LintConfig {
+            ignore: Some(vec!["ambiguous-name".to_string()]),
+            ..LintConfig::default()
+        };
+        assert!(!is_enabled("ambiguous-name", &config));
+        assert!(is_enabled("max-line-length", &config));
+    }
+}